@@ -0,0 +1,154 @@
+use std::collections::VecDeque;
+
+use crate::phonemes::PhonehashElem;
+
+const ALPHABET_SIZE: usize = 8;
+
+#[inline]
+fn symbol_index(elem: PhonehashElem) -> usize {
+	elem as u8 as usize
+}
+
+#[derive(Debug, Clone)]
+struct AutomatonNode {
+	children: [Option<u32>; ALPHABET_SIZE],
+	fail: u32,
+	outputs: Vec<u32>,
+}
+impl AutomatonNode {
+	fn root() -> Self {
+		Self { children: [None; ALPHABET_SIZE], fail: 0, outputs: Vec::new() }
+	}
+}
+
+/// A match reported by [`PhonemeAutomaton::scan`]: `query_index` (the position the pattern was
+/// passed to [`PhonemeAutomaton::build`] in) occurs ending at phoneme `position` in the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutomatonMatch {
+	pub position: usize,
+	pub query_index: usize,
+}
+
+/// An Aho-Corasick automaton over the 8-symbol phoneme alphabet, for finding every occurrence of
+/// a large set of query phoneme sequences inside a single document in one pass.
+///
+/// Patterns and the document must both come from [`crate::phonemes::Phonehash::decode`], not the
+/// raw `phonehash_elements` output: it's `PhonehashRepr::append` (which backs `decode`) that drops
+/// spaces, vowels and consecutive duplicates, not `phonehash_elements` itself. Feeding raw
+/// `phonehash_elements()` patterns here will silently fail to match a `decode()`'d document (or
+/// vice versa) on any word containing a vowel or space.
+#[derive(Debug, Clone)]
+pub struct PhonemeAutomaton {
+	nodes: Vec<AutomatonNode>,
+}
+impl PhonemeAutomaton {
+	/// Compiles a set of query phoneme sequences into an automaton. A document scanned with
+	/// [`Self::scan`] reports a match for `query_index` at every position one of these patterns
+	/// ends.
+	pub fn build<I, P>(patterns: I) -> Self
+	where
+		I: IntoIterator<Item = P>,
+		P: IntoIterator<Item = PhonehashElem>,
+	{
+		let mut nodes = vec![AutomatonNode::root()];
+		for (query_index, pattern) in patterns.into_iter().enumerate() {
+			let mut current = 0u32;
+			for elem in pattern {
+				let sym = symbol_index(elem);
+				current = match nodes[current as usize].children[sym] {
+					Some(child) => child,
+					None => {
+						nodes.push(AutomatonNode::root());
+						let new_index = (nodes.len() - 1) as u32;
+						nodes[current as usize].children[sym] = Some(new_index);
+						new_index
+					},
+				};
+			}
+			nodes[current as usize].outputs.push(query_index as u32);
+		}
+
+		// BFS over the trie to compute failure links, unioning each node's outputs with its
+		// failure target's so a match of a shorter pattern that's also a suffix still fires.
+		let mut queue = VecDeque::new();
+		for sym in 0..ALPHABET_SIZE {
+			if let Some(child) = nodes[0].children[sym] {
+				nodes[child as usize].fail = 0;
+				queue.push_back(child);
+			}
+		}
+		while let Some(current) = queue.pop_front() {
+			for sym in 0..ALPHABET_SIZE {
+				let Some(child) = nodes[current as usize].children[sym] else {
+					continue;
+				};
+				let mut fail = nodes[current as usize].fail;
+				let target = loop {
+					if let Some(fail_child) = nodes[fail as usize].children[sym] {
+						break fail_child;
+					} else if fail == 0 {
+						break 0;
+					} else {
+						fail = nodes[fail as usize].fail;
+					}
+				};
+				nodes[child as usize].fail = target;
+				let fail_outputs = nodes[target as usize].outputs.clone();
+				nodes[child as usize].outputs.extend(fail_outputs);
+				queue.push_back(child);
+			}
+		}
+
+		Self { nodes }
+	}
+
+	/// Streams a document's decoded phoneme sequence through the automaton, reporting every
+	/// position a query pattern ends and which query matched there.
+	pub fn scan(&self, document: impl IntoIterator<Item = PhonehashElem>) -> Vec<AutomatonMatch> {
+		let mut matches = Vec::new();
+		let mut current = 0u32;
+		for (position, elem) in document.into_iter().enumerate() {
+			let sym = symbol_index(elem);
+			loop {
+				if let Some(child) = self.nodes[current as usize].children[sym] {
+					current = child;
+					break;
+				} else if current == 0 {
+					break;
+				} else {
+					current = self.nodes[current as usize].fail;
+				}
+			}
+			for &query_index in &self.nodes[current as usize].outputs {
+				matches.push(AutomatonMatch { position, query_index: query_index as usize });
+			}
+		}
+		matches
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::phonemes::CanPhonehash;
+
+	#[test]
+	fn it_werks() {
+		let queries: Vec<Vec<PhonehashElem>> = ["knight", "rider", "bogus"]
+			.iter()
+			.map(|q| q.phonehash::<u64>().decode())
+			.collect();
+		let automaton = PhonemeAutomaton::build(queries);
+
+		let document = "a knight rider story".phonehash::<u64>().decode();
+		let matches = automaton.scan(document);
+
+		let matched_queries: Vec<usize> = {
+			let mut indices: Vec<usize> = matches.iter().map(|m| m.query_index).collect();
+			indices.sort_unstable();
+			indices.dedup();
+			indices
+		};
+		assert_eq!(matched_queries, vec![0, 1]);
+	}
+}
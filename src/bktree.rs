@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use crate::phonemes::PhonehashElem;
+use crate::search::SearchableItem;
+
+/// Levenshtein distance between two decoded phoneme sequences. This (unlike Damerau-Levenshtein
+/// on the raw strings) satisfies the triangle inequality, which is what a BK-tree relies on to
+/// prune its search.
+fn levenshtein(a: &[PhonehashElem], b: &[PhonehashElem]) -> usize {
+	let mut prev: Vec<usize> = (0..=b.len()).collect();
+	let mut curr = vec![0usize; b.len() + 1];
+	for (i, &ai) in a.iter().enumerate() {
+		curr[0] = i + 1;
+		for (j, &bj) in b.iter().enumerate() {
+			let cost = if ai == bj { 0 } else { 1 };
+			curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+		}
+		std::mem::swap(&mut prev, &mut curr);
+	}
+	prev[b.len()]
+}
+
+struct BkNode<Item> {
+	item: Item,
+	phonemes: Vec<PhonehashElem>,
+	children: HashMap<usize, usize>,
+}
+
+/// A BK-tree indexing items by the Levenshtein distance between their *decoded* phoneme
+/// sequences, for approximate nearest-neighbor lookup when nothing shares an exact phoneme
+/// prefix with the query (unlike `SearchableList::phonehash_search`, which requires that).
+pub struct PhonehashBkTree<Item: SearchableItem> {
+	nodes: Vec<BkNode<Item>>,
+}
+impl<Item: SearchableItem> Default for PhonehashBkTree<Item> {
+	fn default() -> Self {
+		Self { nodes: Vec::new() }
+	}
+}
+impl<Item: SearchableItem> PhonehashBkTree<Item> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Inserts `item` under the root, descending into the child edge labeled by its distance to
+	/// each visited node (creating that edge if it doesn't exist yet).
+	pub fn insert(&mut self, item: Item) {
+		let phonemes = item.as_phoneme().decode();
+		if self.nodes.is_empty() {
+			self.nodes.push(BkNode { item, phonemes, children: HashMap::new() });
+			return;
+		}
+		let mut current = 0usize;
+		loop {
+			let dist = levenshtein(&self.nodes[current].phonemes, &phonemes);
+			match self.nodes[current].children.get(&dist) {
+				Some(&child) => current = child,
+				None => {
+					let new_index = self.nodes.len();
+					self.nodes.push(BkNode { item, phonemes, children: HashMap::new() });
+					self.nodes[current].children.insert(dist, new_index);
+					return;
+				},
+			}
+		}
+	}
+
+	/// Returns every indexed item whose decoded phoneme sequence is within `tolerance` edits of
+	/// `query`'s, sorted by that distance and, for ties, by `SearchableItem::rank` (the same
+	/// metric `phonehash_search` reranks with, so an override of it applies consistently across
+	/// both index types).
+	pub fn query(&self, query: &Item, tolerance: usize) -> Vec<Item> {
+		if self.nodes.is_empty() {
+			return Vec::new();
+		}
+		let query_phonemes = query.as_phoneme().decode();
+		let mut matches = Vec::new();
+		self.query_node(0, &query_phonemes, tolerance, &mut matches);
+		matches.sort_by(|a: &(usize, Item), b: &(usize, Item)| a.0.cmp(&b.0).then_with(|| a.1.rank(query).cmp(&b.1.rank(query))));
+		matches.into_iter().map(|(_, item)| item).collect()
+	}
+
+	fn query_node(&self, node: usize, query_phonemes: &[PhonehashElem], tolerance: usize, matches: &mut Vec<(usize, Item)>) {
+		let node_ref = &self.nodes[node];
+		let dist = levenshtein(&node_ref.phonemes, query_phonemes);
+		if dist <= tolerance {
+			matches.push((dist, node_ref.item.clone()));
+		}
+		// Triangle-inequality pruning: any match under a child reached via edge `d` is within
+		// `[d - tolerance, d + tolerance]` of `dist`, so only those children can possibly help.
+		let lo = dist.saturating_sub(tolerance);
+		let hi = dist + tolerance;
+		for (&edge, &child) in &node_ref.children {
+			if edge >= lo && edge <= hi {
+				self.query_node(child, query_phonemes, tolerance, matches);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::phonemes::{CanPhonehash, Phonehash};
+
+	#[derive(Debug, Clone, PartialEq, Eq)]
+	struct TestObject {
+		str: &'static str,
+		phoneme: Phonehash<u64>,
+	}
+	impl TestObject {
+		fn new(str: &'static str) -> Self {
+			Self { str, phoneme: str.phonehash() }
+		}
+	}
+	impl SearchableItem for TestObject {
+		type Repr = u64;
+		fn as_phoneme(&self) -> Phonehash<Self::Repr> {
+			self.phoneme
+		}
+		fn as_str(&self) -> &str {
+			self.str
+		}
+	}
+
+	#[test]
+	fn it_werks() {
+		let mut tree = PhonehashBkTree::new();
+		for word in ["knight rider", "nite writer", "neight rheyeder", "the amazing digital circus", "aaaa"] {
+			tree.insert(TestObject::new(word));
+		}
+
+		// "knight rider", "nite writer" and "neight rheyeder" all decode to the same phoneme
+		// sequence, so all three are at distance 0 from the query and found at tolerance 0.
+		let mut found: Vec<&str> = tree.query(&TestObject::new("knight rider"), 0).iter().map(|i| i.str).collect();
+		found.sort_unstable();
+		assert_eq!(found, vec!["knight rider", "neight rheyeder", "nite writer"]);
+
+		// loosening the tolerance picks up "aaaa" (every letter in it is a dropped vowel, so its
+		// phoneme sequence is empty) without reaching all the way to the unrelated sentence
+		let mut found_loose: Vec<&str> = tree.query(&TestObject::new("knight rider"), 6).iter().map(|i| i.str).collect();
+		found_loose.sort_unstable();
+		assert_eq!(found_loose, vec!["aaaa", "knight rider", "neight rheyeder", "nite writer"]);
+
+		// nothing close by should come back empty, not panic
+		assert!(tree.query(&TestObject::new("zzzzzzzzzz"), 0).is_empty());
+	}
+}
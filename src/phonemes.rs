@@ -1,17 +1,17 @@
 use core::fmt::{self, Write as _};
+use std::cmp::Ordering;
 use std::{convert::Infallible, str::FromStr};
 
 use deunicode::AsciiChars;
-use itertools::peek_nth;
 
-pub trait PhonehashRepr: Default + Copy + Clone + Eq + Ord {
+pub trait PhonehashRepr: Default + Clone + Eq + Ord {
 	fn stray_bits() -> u32;
 	fn max_phonemes() -> u32;
 	fn is_finalized(&self) -> bool;
 	fn finalize(&mut self, remaining: u32);
 	fn append(&mut self, elem: PhonehashElem) -> bool;
 	fn phoneme_at(&self, index: u32) -> Option<PhonehashElem>;
-	fn starts_with(&self, other: Self) -> bool;
+	fn starts_with(&self, other: &Self) -> bool;
 }
 
 macro_rules! impl_phonehash_repr_uint {
@@ -66,7 +66,8 @@ macro_rules! impl_phonehash_repr_uint {
 					_ => None,
 				}
 			}
-			fn starts_with(&self, other: Self) -> bool {
+			fn starts_with(&self, other: &Self) -> bool {
+				let other = *other;
 				let mut max_phoneme_bits: Self = !Self::default();
 				while (other & max_phoneme_bits) != 0 {
 					max_phoneme_bits = max_phoneme_bits.overflowing_shr(3).0;
@@ -85,6 +86,82 @@ impl_phonehash_repr_uint!(u64);
 impl_phonehash_repr_uint!(u128);
 impl_phonehash_repr_uint!(usize);
 
+/// A growable, heap-backed `PhonehashRepr` for inputs too long for any fixed-width integer repr
+/// (past ~42 phonemes for `u128`, e.g. sentence- or paragraph-level indexing). Stores one phoneme
+/// code per byte rather than bit-packing, trading a little memory for a plain `Vec`.
+///
+/// Its `Ord` compares the stored codes lexicographically, which is exactly what the fixed-width
+/// reprs do too: they zero-pad unused trailing slots on `finalize`, and `Space` (the lowest-valued
+/// phoneme) is the implicit zero, so comparing two different lengths by lexicographic order gives
+/// the same answer as comparing two zero-padded fixed-width reprs of equal width would. This is
+/// the invariant `SearchableList::phonehash_search`'s binary search relies on.
+#[derive(Debug, Clone, Default)]
+pub struct PhonehashVec {
+	elems: Vec<u8>,
+	finalized: bool,
+}
+impl PhonehashRepr for PhonehashVec {
+	fn stray_bits() -> u32 {
+		0
+	}
+	fn max_phonemes() -> u32 {
+		// Unbounded: there's no fixed capacity to report, and `Display`/`decode` stop at the first
+		// `None` from `phoneme_at` rather than relying on this as an exact length.
+		u32::MAX
+	}
+	fn is_finalized(&self) -> bool {
+		self.finalized
+	}
+	fn finalize(&mut self, _remaining: u32) {
+		self.finalized = true;
+	}
+	fn append(&mut self, elem: PhonehashElem) -> bool {
+		let code = elem as u8;
+		// ignore spaces and vowels, don't have consecutive duplicate elements, same as the
+		// fixed-width reprs above
+		if code <= 1 || self.finalized || self.elems.last() == Some(&code) {
+			return false;
+		}
+		self.elems.push(code);
+		true
+	}
+	fn phoneme_at(&self, index: u32) -> Option<PhonehashElem> {
+		match *self.elems.get(index as usize)? {
+			0 => Some(PhonehashElem::Space),
+			1 => Some(PhonehashElem::A),
+			2 => Some(PhonehashElem::B),
+			3 => Some(PhonehashElem::F),
+			4 => Some(PhonehashElem::S),
+			5 => Some(PhonehashElem::G),
+			6 => Some(PhonehashElem::M),
+			7 => Some(PhonehashElem::W),
+			_ => None,
+		}
+	}
+	fn starts_with(&self, other: &Self) -> bool {
+		self.elems.starts_with(&other.elems)
+	}
+}
+// Manual `PartialEq`/`Eq`/`Ord` so they agree with each other (and with `starts_with`) on
+// comparing `elems` alone, ignoring `finalized`: two otherwise-identical reprs shouldn't compare
+// unequal just because one hasn't been finalized yet.
+impl PartialEq for PhonehashVec {
+	fn eq(&self, other: &Self) -> bool {
+		self.elems == other.elems
+	}
+}
+impl Eq for PhonehashVec {}
+impl PartialOrd for PhonehashVec {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+impl Ord for PhonehashVec {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.elems.cmp(&other.elems)
+	}
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Phonehash<T: PhonehashRepr>(pub(crate) T);
@@ -94,8 +171,28 @@ impl<T: PhonehashRepr> Phonehash<T> {
 		phonehash_elements(s).collect()
 	}
 	/// it's like `str::starts_with` but more fuzzy and based on the phoneme hash
-	pub fn starts_with(&self, other: Self) -> bool {
-		self.0.starts_with(other.0)
+	pub fn starts_with(&self, other: &Self) -> bool {
+		self.0.starts_with(&other.0)
+	}
+	/// Decodes the hash back into the sequence of phonemes it represents, trimming the trailing
+	/// `Space` padding `finalize` leaves in the unused slots of a fixed-width repr (real embedded spaces are never
+	/// stored in the first place, see `PhonehashRepr::append`, so any padding only ever shows up
+	/// at the end).
+	pub fn decode(&self) -> Vec<PhonehashElem> {
+		// Stop at the first `None` rather than ranging over the full `0..max_phonemes()`: for
+		// growable reprs like `PhonehashVec`, `max_phonemes` is effectively unbounded and the real
+		// length is whatever `phoneme_at` can still answer for.
+		let mut elems = Vec::new();
+		for i in 0..T::max_phonemes() {
+			match self.0.phoneme_at(i) {
+				Some(pelem) => elems.push(pelem),
+				None => break,
+			}
+		}
+		while matches!(elems.last(), Some(PhonehashElem::Space)) {
+			elems.pop();
+		}
+		elems
 	}
 }
 impl<T: PhonehashRepr> FromStr for Phonehash<T> {
@@ -123,11 +220,14 @@ impl<T: PhonehashRepr> FromIterator<PhonehashElem> for Phonehash<T> {
 }
 impl<T: PhonehashRepr> fmt::Display for Phonehash<T> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Fixed-width reprs always return `Some` for every index below `max_phonemes`, so this
+		// only ever stops early for growable reprs (like `PhonehashVec`) whose "max" is effectively
+		// unbounded and whose actual length is whatever `phoneme_at` can still answer for.
 		for i in 0..T::max_phonemes() {
-			self.0
-				.phoneme_at(i)
-				.map(|pelem| pelem.fmt(f))
-				.unwrap_or_else(|| f.write_char('?'))?;
+			match self.0.phoneme_at(i) {
+				Some(pelem) => pelem.fmt(f)?,
+				None => break,
+			}
 		}
 
 		Ok(())
@@ -167,76 +267,146 @@ impl fmt::Display for PhonehashElem {
 	}
 }
 
-pub fn phonehash_elements(s: &str) -> impl Iterator<Item = PhonehashElem> {
-	// Normalize string to lower case ascii alphabet
-	let mut ascii_alphas = peek_nth(
-		s.ascii_chars()
-			.flat_map(|s| s.unwrap_or_default().chars())
-			.map(|c| (c as u8).to_ascii_lowercase())
-			.flat_map(|c| -> Box<dyn Iterator<Item = u8>> {
-				match c {
-					b'0' => Box::new(b" zero ".iter().copied()),
-					b'1' => Box::new(b" one ".iter().copied()),
-					b'2' => Box::new(b" two ".iter().copied()),
-					b'3' => Box::new(b" three ".iter().copied()),
-					b'4' => Box::new(b" four ".iter().copied()),
-					b'5' => Box::new(b" five ".iter().copied()),
-					b'6' => Box::new(b" six ".iter().copied()),
-					b'7' => Box::new(b" seven ".iter().copied()),
-					b'8' => Box::new(b" eight ".iter().copied()),
-					b'9' => Box::new(b" nine ".iter().copied()),
-					b'$' => Box::new(b" dollar ".iter().copied()),
-					b'%' => Box::new(b" percent ".iter().copied()),
-					b'&' => Box::new(b" and ".iter().copied()),
-					b'+' => Box::new(b" plus ".iter().copied()),
-					// lowkey annoying that I can only erase the iter type by doing a dyn
-					b'a'..=b'z' => Box::new(std::iter::once(c)),
-					_ => Box::new(std::iter::once(b' ')),
-				}
-			}),
-	);
+/// Classification of a single lowercased ascii byte used by the normalization stage below: pass
+/// it through as-is, spell it out (digits, `$`, `%`, ...), or collapse it to a word-separating
+/// space (anything else, e.g. punctuation).
+#[derive(Clone, Copy)]
+enum ByteClass {
+	Alpha(u8),
+	Expand(&'static [u8]),
+	Blank,
+}
+
+const MAX_EXPANSION_LEN: usize = b" percent ".len();
+
+const fn build_byte_classes() -> [ByteClass; 256] {
+	let mut table = [ByteClass::Blank; 256];
+	let mut b = b'a';
+	while b <= b'z' {
+		table[b as usize] = ByteClass::Alpha(b);
+		b += 1;
+	}
+	table[b'0' as usize] = ByteClass::Expand(b" zero ");
+	table[b'1' as usize] = ByteClass::Expand(b" one ");
+	table[b'2' as usize] = ByteClass::Expand(b" two ");
+	table[b'3' as usize] = ByteClass::Expand(b" three ");
+	table[b'4' as usize] = ByteClass::Expand(b" four ");
+	table[b'5' as usize] = ByteClass::Expand(b" five ");
+	table[b'6' as usize] = ByteClass::Expand(b" six ");
+	table[b'7' as usize] = ByteClass::Expand(b" seven ");
+	table[b'8' as usize] = ByteClass::Expand(b" eight ");
+	table[b'9' as usize] = ByteClass::Expand(b" nine ");
+	table[b'$' as usize] = ByteClass::Expand(b" dollar ");
+	table[b'%' as usize] = ByteClass::Expand(b" percent ");
+	table[b'&' as usize] = ByteClass::Expand(b" and ");
+	table[b'+' as usize] = ByteClass::Expand(b" plus ");
+	table
+}
+static BYTE_CLASSES: [ByteClass; 256] = build_byte_classes();
+
+/// Normalizes a (lowercased ascii) char stream into bytes, inlining the handful of multi-byte
+/// expansions (digits, `$`, `%`, ...) into a stack buffer instead of boxing a per-character
+/// iterator.
+struct NormalizedBytes<I> {
+	chars: I,
+	buf: [u8; MAX_EXPANSION_LEN],
+	buf_len: u8,
+	buf_pos: u8,
+}
+impl<I: Iterator<Item = char>> NormalizedBytes<I> {
+	fn new(chars: I) -> Self {
+		Self { chars, buf: [0; MAX_EXPANSION_LEN], buf_len: 0, buf_pos: 0 }
+	}
+}
+impl<I: Iterator<Item = char>> Iterator for NormalizedBytes<I> {
+	type Item = u8;
+	fn next(&mut self) -> Option<u8> {
+		if self.buf_pos < self.buf_len {
+			let b = self.buf[self.buf_pos as usize];
+			self.buf_pos += 1;
+			return Some(b);
+		}
+		let c = (self.chars.next()? as u8).to_ascii_lowercase();
+		match BYTE_CLASSES[c as usize] {
+			ByteClass::Alpha(b) => Some(b),
+			ByteClass::Blank => Some(b' '),
+			ByteClass::Expand(bytes) => {
+				self.buf[..bytes.len()].copy_from_slice(bytes);
+				self.buf_len = bytes.len() as u8;
+				self.buf_pos = 1;
+				Some(bytes[0])
+			},
+		}
+	}
+}
 
-	// operations that require 1 char lookahead
-	let mut check_silent_first_letter = true;
-	let ascii_alphas = std::iter::from_fn(move || -> Option<u8> {
+/// Applies the small set of multi-byte pronunciation rules (ph -> f, silent gh, silent
+/// word-initial kn, consecutive-space collapse) and maps the result onto `PhonehashElem`, all in
+/// a single pass over a fixed 2-byte lookahead window instead of a heap-allocated peekable buffer.
+struct PhonehashElements<I> {
+	bytes: NormalizedBytes<I>,
+	window: [Option<u8>; 2],
+	word_start: bool,
+}
+impl<I: Iterator<Item = char>> PhonehashElements<I> {
+	fn new(chars: I) -> Self {
+		let mut bytes = NormalizedBytes::new(chars);
+		let window = [bytes.next(), bytes.next()];
+		Self { bytes, window, word_start: true }
+	}
+	fn advance(&mut self, n: usize) {
+		for _ in 0..n {
+			self.window[0] = self.window[1];
+			self.window[1] = self.bytes.next();
+		}
+	}
+}
+impl<I: Iterator<Item = char>> Iterator for PhonehashElements<I> {
+	type Item = PhonehashElem;
+	fn next(&mut self) -> Option<PhonehashElem> {
 		loop {
-			match (
-				std::mem::take(&mut check_silent_first_letter),
-				ascii_alphas.next(),
-				ascii_alphas.peek_nth(0).copied(),
-				ascii_alphas.peek_nth(1).copied(),
-			) {
-				(_, None, _, _) => break None,
-				// ph is pronouced as f
-				(_, Some(b'p'), Some(b'h'), _) => {
-					ascii_alphas.next(); // h
-					break Some(b'f');
+			let c = self.window[0]?;
+			let n1 = self.window[1];
+			match (c, n1) {
+				// ph is pronounced as f
+				(b'p', Some(b'h')) => {
+					self.advance(2);
+					self.word_start = false;
+					return Some(PhonehashElem::F);
 				},
 				// remove consecutive spaces
-				(_, Some(b' '), Some(b' '), _) => {
-					continue;
+				(b' ', Some(b' ')) => {
+					self.advance(1);
 				},
-				// gh is silent, skip over them
-				(_, Some(b'g'), Some(b'h'), _) => {
-					ascii_alphas.next(); // h
-					continue;
+				// gh is silent, skip over it
+				(b'g', Some(b'h')) => {
+					self.advance(2);
+					self.word_start = false;
 				},
-				// skip over "k" in "knight"
-				(true, Some(b'k'), Some(b'n'), _) => {
-					break ascii_alphas.next(); // n
+				// skip over "k" in word-initial "kn", e.g. "knight"
+				(b'k', Some(b'n')) if self.word_start => {
+					self.advance(1);
 				},
-				// A knight is approaching
-				(_, Some(b' '), Some(b'k'), Some(b'n')) => {
-					check_silent_first_letter = true;
-					break Some(b' ');
+				_ => {
+					self.advance(1);
+					self.word_start = c == b' ';
+					if let Some(elem) = map_byte_to_elem(c) {
+						return Some(elem);
+					}
 				},
-				(_, Some(c), _, _) => break Some(c),
 			}
 		}
-	});
+	}
+}
+
+pub fn phonehash_elements(s: &str) -> impl Iterator<Item = PhonehashElem> {
+	PhonehashElements::new(s.ascii_chars().flat_map(|s| s.unwrap_or_default().chars()))
+}
 
-	// final part
-	ascii_alphas.filter_map(|c| match c {
+/// Maps a single normalized ascii byte (post lookahead rules) onto its phoneme class. Shared by
+/// `phonehash_elements` and the alternate-pronunciation pipeline below so the two never drift.
+fn map_byte_to_elem(c: u8) -> Option<PhonehashElem> {
+	match c {
 		b' ' => Some(PhonehashElem::Space),
 		b'a' | b'e' | b'i' | b'o' | b'u' | b'y' => Some(PhonehashElem::A),
 		b'b' | b'd' | b't' | b'p' => Some(PhonehashElem::B),
@@ -246,7 +416,138 @@ pub fn phonehash_elements(s: &str) -> impl Iterator<Item = PhonehashElem> {
 		b'm' | b'n' => Some(PhonehashElem::M),
 		b'l' | b'r' | b'w' => Some(PhonehashElem::W),
 		_ => None, // h
-	})
+	}
+}
+
+/// Normalizes a string the same way `phonehash_elements` does (ascii transliteration, lower
+/// casing, digit/symbol spelling-out, via the same table-driven `NormalizedBytes`) but
+/// materializes it as a byte buffer so the alternate-pronunciation scanner below can look at it by
+/// index instead of through an iterator.
+fn normalize_bytes(s: &str) -> Vec<u8> {
+	NormalizedBytes::new(s.ascii_chars().flat_map(|s| s.unwrap_or_default().chars())).collect()
+}
+
+/// Collapses a raw resolved phoneme sequence the same way `PhonehashRepr::append` does (drop
+/// spaces and vowels, drop consecutive duplicates), so two candidate resolutions can be compared
+/// for whether they'd actually produce a different `Phonehash`, not just a different `Vec` before
+/// that filtering runs.
+fn encoded_symbols(elems: &[PhonehashElem]) -> Vec<PhonehashElem> {
+	let mut out: Vec<PhonehashElem> = Vec::with_capacity(elems.len());
+	for &elem in elems {
+		if matches!(elem, PhonehashElem::Space | PhonehashElem::A) {
+			continue;
+		}
+		if out.last() == Some(&elem) {
+			continue;
+		}
+		out.push(elem);
+	}
+	out
+}
+
+/// Index of the first ambiguous spelling in `bytes` whose pronunciation genuinely forks given our
+/// 8-symbol alphabet: a silent letter some speakers sound out (word-initial "gn"/"kn"/"wr") or a
+/// "gh" that's silent in one reading and a hard consonant in another (e.g. "ghost" vs "laugh").
+/// Word-initial "wr" is a candidate spelling but never a real fork, since `w` and `r` both map to
+/// `PhonehashElem::W` either way; comparing `encoded_symbols` of each candidate resolution against
+/// the primary's (rather than the raw resolved phonemes) is what catches that no-op, so it can't
+/// mask a later, genuinely ambiguous candidate in the same input.
+fn find_fork_point(bytes: &[u8], primary: &[PhonehashElem]) -> Option<usize> {
+	let primary_encoded = encoded_symbols(primary);
+	for i in 0..bytes.len() {
+		let is_word_start = i == 0 || bytes[i - 1] == b' ';
+		let next = bytes.get(i + 1).copied();
+		let is_candidate = match (bytes[i], next) {
+			(b'g', Some(b'h')) => true,
+			(b'k', Some(b'n')) | (b'g', Some(b'n')) | (b'w', Some(b'r')) => is_word_start,
+			_ => false,
+		};
+		if !is_candidate {
+			continue;
+		}
+		if encoded_symbols(&resolve_pronunciation(bytes, Some(i))) != primary_encoded {
+			return Some(i);
+		}
+	}
+	None
+}
+
+/// Runs the lookahead rules of `phonehash_elements`, plus a silent first letter in word-initial
+/// "gn" and "wr" (so for a word like "gnome" the primary differs from `phonehash_elements`),
+/// except that at `fork_at` (if any) it takes the *other* branch of the ambiguity found there: a
+/// silent "gh"/initial "gn"/"kn"/"wr" becomes pronounced. Everything outside `fork_at` resolves the same way in both
+/// the primary and the alternate pass, which is what keeps the alternate encoding to exactly one
+/// extra `Phonehash` instead of a combinatorial explosion of branches.
+fn resolve_pronunciation(bytes: &[u8], fork_at: Option<usize>) -> Vec<PhonehashElem> {
+	let mut out_bytes: Vec<u8> = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+	while i < bytes.len() {
+		let c = bytes[i];
+		let n1 = bytes.get(i + 1).copied();
+		let is_word_start = i == 0 || bytes[i - 1] == b' ';
+		let at_fork = fork_at == Some(i);
+		let consumed = match (c, n1) {
+			(b'p', Some(b'h')) => {
+				out_bytes.push(b'f');
+				2
+			},
+			(b' ', Some(b' ')) => 1,
+			(b'g', Some(b'h')) => {
+				if at_fork {
+					out_bytes.push(b'g');
+				}
+				2
+			},
+			(b'k', Some(b'n')) if is_word_start => {
+				if at_fork {
+					out_bytes.push(b'k');
+				}
+				out_bytes.push(b'n');
+				2
+			},
+			(b'g', Some(b'n')) if is_word_start => {
+				if at_fork {
+					out_bytes.push(b'g');
+				}
+				out_bytes.push(b'n');
+				2
+			},
+			(b'w', Some(b'r')) if is_word_start => {
+				if at_fork {
+					out_bytes.push(b'w');
+				}
+				out_bytes.push(b'r');
+				2
+			},
+			_ => {
+				out_bytes.push(c);
+				1
+			},
+		};
+		i += consumed;
+	}
+	out_bytes.into_iter().filter_map(map_byte_to_elem).collect()
+}
+
+/// Like `phonehash_elements`, but for spellings with a genuinely ambiguous pronunciation (silent
+/// letters some speakers sound out, "gh" as silent vs a hard consonant) also returns the alternate
+/// reading, mirroring how Double Metaphone emits a primary and secondary code. Only the first
+/// ambiguity that actually changes the phonemes is forked (see `find_fork_point`); `None` means
+/// nothing in the input forked.
+pub fn phonehash_elements_with_alternate(s: &str) -> (Vec<PhonehashElem>, Option<Vec<PhonehashElem>>) {
+	let bytes = normalize_bytes(s);
+	let primary = resolve_pronunciation(&bytes, None);
+	let alternate = find_fork_point(&bytes, &primary).map(|fork_at| resolve_pronunciation(&bytes, Some(fork_at)));
+	(primary, alternate)
+}
+
+/// Calculates the primary phoneme hash of the string, along with an alternate encoding when the
+/// spelling has a genuinely ambiguous pronunciation under `phonehash_elements_with_alternate`.
+/// Use [`crate::search::SearchableItem::as_phoneme_alt`] to expose the alternate to
+/// `phonehash_search`.
+pub fn phonehash_with_alternate<T: PhonehashRepr>(s: &str) -> (Phonehash<T>, Option<Phonehash<T>>) {
+	let (primary, alternate) = phonehash_elements_with_alternate(s);
+	(primary.into_iter().collect(), alternate.map(|alt| alt.into_iter().collect()))
 }
 
 // convenience traits
@@ -295,11 +596,11 @@ mod test {
 			"knight rheyedhurr".phonehash::<u64>().to_string(),
 			"MBWBW________________"
 		);
-		assert!("knight rider".phonehash::<u64>().starts_with("nite".phonehash::<u64>()));
+		assert!("knight rider".phonehash::<u64>().starts_with(&"nite".phonehash::<u64>()));
 		assert!(
 			!"knight"
 				.phonehash::<u64>()
-				.starts_with("nite rheyedhurr".phonehash::<u64>())
+				.starts_with(&"nite rheyedhurr".phonehash::<u64>())
 		);
 
 		// vowel normalization
@@ -314,4 +615,59 @@ mod test {
 		assert_eq!("co-op".phonehash::<u64>().to_string(), "SB___________________");
 		assert_eq!("co   op".phonehash::<u64>().to_string(), "SB___________________");
 	}
+
+	#[test]
+	fn alternate_pronunciation_works() {
+		// "gh" silent (primary) vs. sounded as a hard g (alternate), as in "ghost" vs "laugh"
+		let (primary, alternate) = phonehash_with_alternate::<u64>("ghost");
+		assert_eq!(primary.to_string(), "SB___________________");
+		assert_eq!(alternate.unwrap().to_string(), "GSB__________________");
+
+		// word-initial "gn" silent-g (primary) vs. sounded (alternate), as in "gnome"
+		let (primary, alternate) = phonehash_with_alternate::<u64>("gnome");
+		assert_eq!(primary.to_string(), "M____________________");
+		assert_eq!(alternate.unwrap().to_string(), "GM___________________");
+
+		// unambiguous spellings don't get an alternate at all
+		let (primary, alternate) = phonehash_with_alternate::<u64>("phoenix");
+		assert_eq!(primary.to_string(), "FMS__________________");
+		assert!(alternate.is_none());
+	}
+
+	#[test]
+	fn fork_point_skips_no_op_candidates() {
+		// word-initial "wr" is a candidate spelling, but "w" and "r" both map to the same
+		// phoneme (`W`), so forking there is a no-op; the scan must keep going to the genuinely
+		// ambiguous "gh" in "ghost" instead of stopping (and reporting no alternate) at "wr".
+		let (primary, alternate) = phonehash_with_alternate::<u64>("wrangle ghost");
+		assert_eq!(primary.to_string(), "WMGWSB_______________");
+		assert_eq!(alternate.unwrap().to_string(), "WMGWGSB______________");
+
+		// same story for a short two-word phrase
+		let (primary, alternate) = phonehash_with_alternate::<u64>("write ghost");
+		assert_eq!(primary.to_string(), "WBSB_________________");
+		assert_eq!(alternate.unwrap().to_string(), "WBGSB________________");
+	}
+
+	#[test]
+	fn phonehash_vec_orders_like_fixed_width() {
+		// No ~42-phoneme ceiling: `u128` (the widest fixed-width repr) truncates this, `PhonehashVec` doesn't.
+		let sentence = "the quick brown fox jumps over the lazy dog and then some more distinctive words \
+			to make sure this sentence produces well over forty two unique consecutive phoneme symbols total";
+		let long_fixed: Phonehash<u128> = sentence.phonehash();
+		let long_vec: Phonehash<PhonehashVec> = sentence.phonehash();
+		assert!(long_vec.decode().len() > long_fixed.decode().len());
+
+		let short: Phonehash<PhonehashVec> = "knight".phonehash();
+		let longer: Phonehash<PhonehashVec> = "knight rider".phonehash();
+		assert_eq!(short.to_string(), "MB");
+		assert_eq!(longer.to_string(), "MBWBW");
+
+		// `longer`'s extra trailing phonemes are the only difference from `short`'s implicit
+		// zero-padding, so the ordering matches what the same comparison would give for a
+		// fixed-width repr padded out to a common length.
+		assert!(short < longer);
+		assert!(longer.starts_with(&short));
+		assert!(!short.starts_with(&longer));
+	}
 }
@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 
 use strsim::damerau_levenshtein;
 
+use crate::automaton::PhonemeAutomaton;
 use crate::phonemes::{Phonehash, PhonehashRepr};
 
 pub trait SearchableItem: Clone {
@@ -9,6 +10,22 @@ pub trait SearchableItem: Clone {
 
 	fn as_phoneme(&self) -> Phonehash<Self::Repr>;
 	fn as_str(&self) -> &str;
+
+	/// An alternate phonetic encoding for this item, for spellings whose pronunciation genuinely
+	/// forks (see `crate::phonemes::phonehash_with_alternate`). Defaults to `None`, which keeps
+	/// `phonehash_search` on the single-encoding fast path for existing implementors.
+	fn as_phoneme_alt(&self) -> Option<Phonehash<Self::Repr>> {
+		None
+	}
+
+	/// Distance used to rerank candidates that share a phoneme hash with `query`, smallest first.
+	/// Defaults to Damerau-Levenshtein over the raw strings, matching `phonehash_search`'s
+	/// original behavior; override for another integer distance, e.g. one computed directly on the
+	/// decoded phonemes. Fractional scores (Jaro-Winkler, a normalized `[0, 1]` score) don't fit a
+	/// `usize`; `SearchableList::phonehash_search_by` takes any `PartialOrd` rank for those.
+	fn rank(&self, query: &Self) -> usize {
+		damerau_levenshtein(self.as_str(), query.as_str())
+	}
 }
 
 pub trait SearchableList {
@@ -22,7 +39,49 @@ pub trait SearchableList {
 	unsafe fn item_at_unchecked(&self, index: usize) -> &Self::ListItem;
 
 	/// Search this list for the following query. This function assumes that the list is sorted by the phoneme hash
+	///
+	/// An item is considered a match if either its primary or its alternate encoding
+	/// (`SearchableItem::as_phoneme_alt`) matches either of the query's encodings. Since the list
+	/// is only sorted by the primary phoneme, this runs the binary search once per query encoding
+	/// (one or two); an item whose *alternate* phoneme happens to match but whose primary phoneme
+	/// sorts far from both searched positions still won't be found, same as any other index that
+	/// isn't keyed on the field you're filtering by.
 	fn phonehash_search(&self, query: &Self::ListItem, max_items: usize) -> Vec<Self::ListItem> {
+		self.phonehash_search_by(query, max_items, |candidate, query| candidate.rank(query))
+	}
+
+	/// Like `phonehash_search`, but reranks candidates with `rank` instead of `SearchableItem::rank`,
+	/// for a one-off metric (Jaro-Winkler, a normalized score, a metric over decoded phonemes...)
+	/// without implementing it on `SearchableItem` itself.
+	fn phonehash_search_by<R, F>(&self, query: &Self::ListItem, max_items: usize, rank: F) -> Vec<Self::ListItem>
+	where
+		R: PartialOrd,
+		F: Fn(&Self::ListItem, &Self::ListItem) -> R,
+	{
+		let mut result_with_dist = self.phonehash_search_by_key(query.as_phoneme(), query, max_items, &rank);
+		if let Some(alt_key) = query.as_phoneme_alt()
+			&& alt_key != query.as_phoneme()
+		{
+			for candidate in self.phonehash_search_by_key(alt_key, query, max_items, &rank) {
+				if !result_with_dist.iter().any(|(_, existing)| existing.as_str() == candidate.1.as_str()) {
+					result_with_dist.push(candidate);
+				}
+			}
+		}
+		result_with_dist.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+		result_with_dist.into_iter().take(max_items).map(|v| v.1).collect()
+	}
+
+	/// Core of `phonehash_search_by`, parameterized over which phoneme hash to binary-search and
+	/// compare against. Not intended to be called directly; `phonehash_search_by` runs this once
+	/// per encoding the query exposes and merges the results.
+	fn phonehash_search_by_key<R>(
+		&self,
+		key: Phonehash<<Self::ListItem as SearchableItem>::Repr>,
+		query: &Self::ListItem,
+		max_items: usize,
+		rank: impl Fn(&Self::ListItem, &Self::ListItem) -> R,
+	) -> Vec<(R, Self::ListItem)> {
 		// based on the rust stdlib's "binary_search_by" algorithm
 		let mut size = self.len();
 		if size == 0 {
@@ -34,7 +93,7 @@ pub trait SearchableList {
 			let half = size / 2;
 			let mid = base + half;
 
-			let cmp = unsafe { self.item_at_unchecked(mid).as_phoneme() }.cmp(&query.as_phoneme());
+			let cmp = unsafe { self.item_at_unchecked(mid).as_phoneme() }.cmp(&key);
 			if cmp == Ordering::Less {
 				base = mid + 1;
 				size -= half + 1;
@@ -51,23 +110,42 @@ pub trait SearchableList {
 		while base < size {
 			// SAFETY: base < size is explicitly checked
 			let base_value = unsafe { self.item_at_unchecked(base).clone() };
-			if base_value.as_phoneme() != query.as_phoneme() {
+			let matches = base_value.as_phoneme() == key || base_value.as_phoneme_alt().is_some_and(|alt| alt == key);
+			if !matches {
 				break;
 			}
-			result_with_dist.push((damerau_levenshtein(base_value.as_str(), query.as_str()), base_value));
+			let dist = rank(&base_value, query);
+			result_with_dist.push((dist, base_value));
 			base += 1;
 		}
 		while base < max_item_index {
 			// SAFETY: max_item_index <= size, base < max_item_index
 			let base_value = unsafe { self.item_at_unchecked(base).clone() };
-			if !base_value.as_phoneme().starts_with(query.as_phoneme()) {
+			let matches = base_value.as_phoneme().starts_with(&key)
+				|| base_value.as_phoneme_alt().is_some_and(|alt| alt.starts_with(&key));
+			if !matches {
 				break;
 			}
-			result_with_dist.push((damerau_levenshtein(base_value.as_str(), query.as_str()), base_value));
+			let dist = rank(&base_value, query);
+			result_with_dist.push((dist, base_value));
 			base += 1;
 		}
-		result_with_dist.sort_by(|a, b| a.0.cmp(&b.0));
-		result_with_dist.into_iter().take(max_items).map(|v| v.1).collect()
+		result_with_dist
+	}
+
+	/// Bulk-tags every item in this list with the indices of the `automaton`'s query patterns
+	/// that occur anywhere in the item's decoded phoneme sequence, in one pass per item rather
+	/// than a phonehash_search per query.
+	fn phonehash_tag_matches(&self, automaton: &PhonemeAutomaton) -> Vec<Vec<usize>> {
+		(0..self.len())
+			.map(|index| {
+				let decoded = unsafe { self.item_at_unchecked(index) }.as_phoneme().decode();
+				let mut matched: Vec<usize> = automaton.scan(decoded).into_iter().map(|m| m.query_index).collect();
+				matched.sort_unstable();
+				matched.dedup();
+				matched
+			})
+			.collect()
 	}
 }
 impl<T: SearchableItem> SearchableList for [T] {
@@ -184,4 +262,87 @@ mod test {
 			vec![TestObject::new("knight rider"), TestObject::new("nite writer")]
 		);
 	}
+
+	#[test]
+	fn phonehash_search_by_uses_custom_rank() {
+		#[derive(Debug, Clone, PartialEq, Eq)]
+		struct TestObject {
+			str: &'static str,
+			phoneme: Phonehash<u64>,
+		}
+		impl TestObject {
+			pub fn new(str: &'static str) -> Self {
+				Self { str, phoneme: str.phonehash() }
+			}
+		}
+		impl SearchableItem for TestObject {
+			type Repr = u64;
+			fn as_phoneme(&self) -> Phonehash<Self::Repr> {
+				self.phoneme
+			}
+			fn as_str(&self) -> &str {
+				self.str
+			}
+		}
+
+		let mut stuff = vec![
+			TestObject::new("knight rider"),
+			TestObject::new("nite writer"),
+			TestObject::new("neight rheyeder"),
+		];
+		stuff.sort_by_key(|v| v.phoneme);
+
+		// with the default `rank` (Damerau-Levenshtein on the raw strings), the exact match comes
+		// first; "nite writer" and "neight rheyeder" tie at distance 6 and keep their list order.
+		assert_eq!(
+			stuff.phonehash_search(&TestObject::new("knight rider"), 3),
+			vec![TestObject::new("knight rider"), TestObject::new("nite writer"), TestObject::new("neight rheyeder")]
+		);
+
+		// reranking by raw string length (shortest first), ignoring the query entirely, flips
+		// that order since it no longer favors the exact match.
+		assert_eq!(
+			stuff.phonehash_search_by(&TestObject::new("knight rider"), 3, |candidate, _query| candidate.as_str().len()),
+			vec![TestObject::new("nite writer"), TestObject::new("knight rider"), TestObject::new("neight rheyeder")]
+		);
+	}
+
+	#[test]
+	fn phonehash_tag_matches_works() {
+		#[derive(Debug, Clone, PartialEq, Eq)]
+		struct TestObject {
+			str: &'static str,
+			phoneme: Phonehash<u64>,
+		}
+		impl TestObject {
+			pub fn new(str: &'static str) -> Self {
+				Self { str, phoneme: str.phonehash() }
+			}
+		}
+		impl SearchableItem for TestObject {
+			type Repr = u64;
+			fn as_phoneme(&self) -> Phonehash<Self::Repr> {
+				self.phoneme
+			}
+			fn as_str(&self) -> &str {
+				self.str
+			}
+		}
+
+		let stuff = vec![
+			TestObject::new("knight rider"),
+			TestObject::new("the amazing digital circus"),
+			TestObject::new("nite writer"),
+		];
+
+		// `phonehash_tag_matches` scans each item's *decoded* phoneme sequence, so the automaton's
+		// patterns need to go through the same filtering (no spaces/vowels, no consecutive
+		// duplicates) rather than the raw `phonehash_elements` output.
+		let automaton = PhonemeAutomaton::build([
+			"knight".phonehash::<u64>().decode(),
+			"circus".phonehash::<u64>().decode(),
+		]);
+
+		assert_eq!(stuff.phonehash_tag_matches(&automaton), vec![vec![0], vec![1], vec![0]]);
+	}
 }